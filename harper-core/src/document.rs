@@ -0,0 +1,219 @@
+use crate::parsers::Parser;
+use crate::{Span, TokenBuffer};
+
+/// An in-memory source buffer paired with the [`TokenBuffer`] it lexes to.
+///
+/// Beyond one-shot construction via [`Document::new`], `Document` supports
+/// incremental reparsing through [`Document::apply_edit`], so that
+/// editor/LSP-style keystroke-by-keystroke edits don't force a full
+/// re-tokenize of the whole source.
+pub struct Document {
+    source: Vec<char>,
+    tokens: TokenBuffer,
+}
+
+/// Whether [`Document::apply_edit`] spliced in a reparsed paragraph, or had
+/// to fall back to a full reparse of the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReparseKind {
+    Incremental,
+    Full,
+}
+
+/// The span of a paragraph -- the source between two `Newline` tokens (or a
+/// document boundary) -- along with the index range of its tokens.
+struct Paragraph {
+    span: Span,
+    token_start: usize,
+    token_end: usize,
+}
+
+impl Document {
+    pub fn new(source: &str, parser: &mut impl Parser) -> Self {
+        let source: Vec<char> = source.chars().collect();
+        let tokens = parser.parse(&source);
+        Self { source, tokens }
+    }
+
+    pub fn source(&self) -> &[char] {
+        &self.source
+    }
+
+    pub fn tokens(&self) -> &TokenBuffer {
+        &self.tokens
+    }
+
+    /// Replace the characters in `edit_range` with `replacement`, updating
+    /// `self.tokens` to match.
+    ///
+    /// Rather than re-running `parser` over the whole edited source, this
+    /// first tries to relex only the paragraph the edit falls in -- the
+    /// region between the `Newline` tokens immediately surrounding
+    /// `edit_range` -- and splice the resulting tokens in place of the old
+    /// ones, shifting every token after the edit by the length delta. If the
+    /// edit crosses a paragraph boundary, reparsing the paragraph produces a
+    /// new `Newline` (i.e. the edit changed the block structure), or
+    /// `parser` isn't [`Parser::is_paragraph_local`], this falls back to a
+    /// full reparse so correctness never depends on the heuristic.
+    pub fn apply_edit(
+        &mut self,
+        edit_range: Span,
+        replacement: &[char],
+        parser: &mut impl Parser,
+    ) -> ReparseKind {
+        if parser.is_paragraph_local()
+            && self
+                .try_incremental_reparse(edit_range, replacement, parser)
+                .is_some()
+        {
+            return ReparseKind::Incremental;
+        }
+
+        self.source
+            .splice(edit_range.start..edit_range.end, replacement.iter().copied());
+        self.tokens = parser.parse(&self.source);
+        ReparseKind::Full
+    }
+
+    fn try_incremental_reparse(
+        &mut self,
+        edit_range: Span,
+        replacement: &[char],
+        parser: &mut impl Parser,
+    ) -> Option<()> {
+        let block = self.enclosing_paragraph(edit_range)?;
+
+        let mut new_source: Vec<char> = self.source[block.span.start..edit_range.start].to_vec();
+        new_source.extend_from_slice(replacement);
+        new_source.extend_from_slice(&self.source[edit_range.end..block.span.end]);
+
+        let mut new_tokens = parser.parse(&new_source);
+
+        if new_tokens.is_empty() {
+            // The edit emptied the paragraph, so the `Newline` tokens on
+            // either side of it would now be adjacent; a full parse merges
+            // them into one.
+            return None;
+        }
+
+        if new_tokens.iter().any(|token| token.kind.is_newline()) {
+            // The edit introduced (or exposed) a paragraph break inside what
+            // we assumed was a single block, so the `Newline` tokens we
+            // spliced around no longer bound a single paragraph.
+            return None;
+        }
+
+        new_tokens.push_by(block.span.start);
+
+        let delta = replacement.len() as isize - edit_range.len() as isize;
+        self.source
+            .splice(edit_range.start..edit_range.end, replacement.iter().copied());
+
+        self.tokens.shift_from(block.token_end, delta);
+        self.tokens
+            .splice(block.token_start..block.token_end, new_tokens);
+
+        Some(())
+    }
+
+    /// Find the paragraph -- the run of tokens between `Newline` boundaries
+    /// -- that fully encloses `edit_range`.
+    fn enclosing_paragraph(&self, edit_range: Span) -> Option<Paragraph> {
+        let mut token_start = 0;
+        let mut span_start = 0;
+
+        for (i, token) in self.tokens.iter().enumerate() {
+            if token.kind.is_newline() {
+                let span = Span::new(span_start, token.span.start);
+                if span.contains(&edit_range) {
+                    return Some(Paragraph {
+                        span,
+                        token_start,
+                        token_end: i,
+                    });
+                }
+                token_start = i + 1;
+                span_start = token.span.end;
+            }
+        }
+
+        let span = Span::new(span_start, self.source.len());
+        span.contains(&edit_range).then_some(Paragraph {
+            span,
+            token_start,
+            token_end: self.tokens.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::{PlainEnglish, Typst};
+
+    /// The invariant every `Document` must hold: tokens are contiguous and
+    /// cover the whole source, with no gaps or overlaps.
+    fn contiguous_and_complete(doc: &Document) -> bool {
+        let mut cursor = 0;
+        for token in doc.tokens().iter() {
+            if token.span.start != cursor {
+                return false;
+            }
+            cursor = token.span.end;
+        }
+        cursor == doc.source().len()
+    }
+
+    #[test]
+    fn edit_within_a_paragraph_is_incremental() {
+        let mut parser = PlainEnglish;
+        let mut doc = Document::new("This is a test.", &mut parser);
+
+        let kind = doc.apply_edit(Span::new(10, 14), &['b', 'e', 's', 't'], &mut parser);
+
+        assert_eq!(kind, ReparseKind::Incremental);
+        assert_eq!(
+            doc.source().iter().collect::<String>(),
+            "This is a best."
+        );
+        assert!(contiguous_and_complete(&doc));
+    }
+
+    #[test]
+    fn edit_crossing_a_paragraph_break_falls_back_to_full_reparse() {
+        let mut parser = PlainEnglish;
+        let mut doc = Document::new("First.\nSecond.", &mut parser);
+
+        // "t.\nS" spans the paragraph break between the two sentences.
+        let kind = doc.apply_edit(Span::new(4, 8), &['X'], &mut parser);
+
+        assert_eq!(kind, ReparseKind::Full);
+        assert_eq!(doc.source().iter().collect::<String>(), "FirsXecond.");
+        assert!(contiguous_and_complete(&doc));
+    }
+
+    #[test]
+    fn edit_introducing_a_newline_falls_back_to_full_reparse() {
+        let mut parser = PlainEnglish;
+        let mut doc = Document::new("This is a test.", &mut parser);
+
+        let kind = doc.apply_edit(Span::new(4, 4), &['\n'], &mut parser);
+
+        assert_eq!(kind, ReparseKind::Full);
+        assert!(contiguous_and_complete(&doc));
+    }
+
+    #[test]
+    fn non_paragraph_local_parser_always_does_a_full_reparse() {
+        // Typst doesn't opt into `is_paragraph_local`, since a `Newline` can
+        // sit inside a single bracketed construct (e.g. `#emph[a\nb]`), so
+        // `apply_edit` must never take the incremental path for it.
+        let mut parser = Typst;
+        let mut doc = Document::new("This is a test.", &mut parser);
+
+        let kind = doc.apply_edit(Span::new(10, 14), &['b', 'e', 's', 't'], &mut parser);
+
+        assert_eq!(kind, ReparseKind::Full);
+        assert!(contiguous_and_complete(&doc));
+    }
+}
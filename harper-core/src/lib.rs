@@ -8,9 +8,9 @@ mod span;
 mod spell;
 mod token;
 
-pub use document::Document;
+pub use document::{Document, ReparseKind};
 pub use linting::LintSet;
 pub use linting::{Lint, LintKind, Linter, Suggestion};
 pub use span::Span;
 pub use spell::Dictionary;
-pub use token::{FatToken, Punctuation, Token, TokenKind, TokenStringExt};
+pub use token::{FatToken, Punctuation, Token, TokenBuffer, TokenKind, TokenStringExt, WordMetadata};
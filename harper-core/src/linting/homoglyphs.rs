@@ -0,0 +1,197 @@
+use super::{Lint, LintKind, Linter, Suggestion};
+use crate::{Span, TokenBuffer, TokenKind};
+
+/// Flags Unicode characters that are visually confusable with an ASCII
+/// lookalike -- smart quotes, dashes, non-breaking spaces, Cyrillic/Greek
+/// homoglyph letters, and fullwidth Latin forms -- and suggests the ASCII
+/// replacement, mirroring the table rustc's `unicode_chars` lint uses for
+/// source files.
+///
+/// Smart quotes are often typed on purpose, so they're opt-in via
+/// `flag_curly_quotes`. Homoglyph letters are only flagged when they sit
+/// inside an otherwise-ASCII/Latin word, since that's the actual
+/// spoofing/typo signal -- a word written entirely in Cyrillic or Greek is
+/// just prose in that script, not a confusable.
+pub struct Homoglyphs {
+    flag_curly_quotes: bool,
+}
+
+impl Homoglyphs {
+    pub fn new(flag_curly_quotes: bool) -> Self {
+        Self { flag_curly_quotes }
+    }
+}
+
+impl Default for Homoglyphs {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+/// A confusable codepoint's ASCII replacement and human-readable name.
+struct Confusable {
+    replacement: String,
+    name: &'static str,
+    is_curly_quote: bool,
+    /// Whether this is a letter that's only a meaningful signal when it's
+    /// hiding inside an otherwise-Latin word, as opposed to punctuation-like
+    /// confusables (quotes, dashes, spaces, fullwidth forms) that are always
+    /// worth flagging wherever they appear.
+    is_letter_homoglyph: bool,
+}
+
+fn confusable(c: char) -> Option<Confusable> {
+    let simple = |replacement: &str, name: &'static str, is_curly_quote: bool| Confusable {
+        replacement: replacement.to_string(),
+        name,
+        is_curly_quote,
+        is_letter_homoglyph: false,
+    };
+    let letter = |replacement: &str, name: &'static str| Confusable {
+        replacement: replacement.to_string(),
+        name,
+        is_curly_quote: false,
+        is_letter_homoglyph: true,
+    };
+
+    Some(match c {
+        '\u{2018}' => simple("'", "curly left single quote", true),
+        '\u{2019}' => simple("'", "curly right single quote", true),
+        '\u{201C}' => simple("\"", "curly left double quote", true),
+        '\u{201D}' => simple("\"", "curly right double quote", true),
+        '\u{2013}' => simple("-", "en dash", false),
+        '\u{2014}' => simple("-", "em dash", false),
+        '\u{00A0}' => simple(" ", "non-breaking space", false),
+        '\u{0430}' => letter("a", "Cyrillic letter 'а' (U+0430)"),
+        '\u{0435}' => letter("e", "Cyrillic letter 'е' (U+0435)"),
+        '\u{043E}' => letter("o", "Cyrillic letter 'о' (U+043E)"),
+        '\u{03BF}' => letter("o", "Greek letter 'ο' (U+03BF, omicron)"),
+        '\u{03C1}' => letter("p", "Greek letter 'ρ' (U+03C1, rho)"),
+        '\u{FF21}'..='\u{FF5A}' => {
+            // Fullwidth Latin forms sit at a fixed offset from their ASCII
+            // counterparts in the Basic Latin block.
+            let ascii = char::from_u32(c as u32 - 0xFEE0)?;
+            simple(&ascii.to_string(), "fullwidth Latin form", false)
+        }
+        _ => return None,
+    })
+}
+
+/// Whether `word` consists entirely of ASCII alphanumerics, aside from
+/// characters that are themselves letter-homoglyphs -- i.e. whether it reads
+/// as a Latin word that a homoglyph letter has been smuggled into.
+fn is_otherwise_latin_word(word: &[char]) -> bool {
+    word.iter().any(|c| c.is_ascii_alphanumeric())
+        && word.iter().all(|&c| {
+        c.is_ascii_alphanumeric() || confusable(c).is_some_and(|found| found.is_letter_homoglyph)
+    })
+}
+
+impl Linter for Homoglyphs {
+    fn lint(&mut self, tokens: &TokenBuffer, source: &[char]) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for token in tokens.iter() {
+            if !matches!(
+                token.kind,
+                TokenKind::Word(_) | TokenKind::Punctuation(_) | TokenKind::Space(_)
+            ) {
+                continue;
+            }
+
+            let content = token.span.get_content(source);
+            let word_is_otherwise_latin = token.kind.is_word() && is_otherwise_latin_word(content);
+
+            for (offset, &c) in content.iter().enumerate() {
+                let Some(found) = confusable(c) else {
+                    continue;
+                };
+
+                if found.is_curly_quote && !self.flag_curly_quotes {
+                    continue;
+                }
+
+                if found.is_letter_homoglyph && !word_is_otherwise_latin {
+                    continue;
+                }
+
+                let index = token.span.start + offset;
+                lints.push(Lint {
+                    span: Span::new(index, index + 1),
+                    lint_kind: LintKind::Style,
+                    suggestions: vec![Suggestion::ReplaceWith(found.replacement.chars().collect())],
+                    message: format!(
+                        "`{c}` is a {}. Did you mean to type `{}`?",
+                        found.name, found.replacement
+                    ),
+                });
+            }
+        }
+
+        lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Homoglyphs;
+    use crate::parsers::{PlainEnglish, StrParser};
+    use crate::{Lint, Linter, Span, Suggestion};
+
+    fn lint(source: &str, homoglyphs: &mut Homoglyphs) -> Vec<Lint> {
+        let chars: Vec<char> = source.chars().collect();
+        let tokens = PlainEnglish.parse_str(source);
+        homoglyphs.lint(&tokens, &chars)
+    }
+
+    #[test]
+    fn flags_curly_quote_when_opted_in() {
+        let mut homoglyphs = Homoglyphs::new(true);
+        let lints = lint("\u{201C}hello\u{201D}", &mut homoglyphs);
+
+        assert_eq!(lints.len(), 2);
+        assert_eq!(lints[0].span, Span::new(0, 1));
+        assert_eq!(
+            lints[0].suggestions,
+            vec![Suggestion::ReplaceWith(vec!['"'])]
+        );
+    }
+
+    #[test]
+    fn does_not_flag_curly_quote_by_default() {
+        let mut homoglyphs = Homoglyphs::default();
+        let lints = lint("\u{201C}hello\u{201D}", &mut homoglyphs);
+
+        assert!(lints.is_empty());
+    }
+
+    #[test]
+    fn flags_non_breaking_space() {
+        let mut homoglyphs = Homoglyphs::default();
+        let lints = lint("a\u{00A0}b", &mut homoglyphs);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].span, Span::new(1, 2));
+        assert_eq!(lints[0].suggestions, vec![Suggestion::ReplaceWith(vec![' '])]);
+    }
+
+    #[test]
+    fn flags_cyrillic_letter_hiding_in_a_latin_word() {
+        let mut homoglyphs = Homoglyphs::default();
+        // "\u{0430}pple" -- a Cyrillic 'а' standing in for a Latin 'a'.
+        let lints = lint("\u{0430}pple", &mut homoglyphs);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].span, Span::new(0, 1));
+        assert_eq!(lints[0].suggestions, vec![Suggestion::ReplaceWith(vec!['a'])]);
+    }
+
+    #[test]
+    fn does_not_flag_a_word_written_entirely_in_cyrillic() {
+        let mut homoglyphs = Homoglyphs::default();
+        // "привет" ("hello") -- legitimate Cyrillic prose, not a spoof.
+        let lints = lint("\u{043F}\u{0440}\u{0438}\u{0432}\u{0435}\u{0442}", &mut homoglyphs);
+
+        assert!(lints.is_empty());
+    }
+}
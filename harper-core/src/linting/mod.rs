@@ -0,0 +1,56 @@
+mod homoglyphs;
+
+pub use homoglyphs::Homoglyphs;
+
+use crate::{Span, TokenBuffer};
+
+/// Something a [`Linter`] found wrong with a span of the document, optionally
+/// along with one or more [`Suggestion`]s for fixing it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lint {
+    pub span: Span,
+    pub lint_kind: LintKind,
+    pub suggestions: Vec<Suggestion>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    Spelling,
+    Formatting,
+    Repetition,
+    Style,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Suggestion {
+    ReplaceWith(Vec<char>),
+    Remove,
+}
+
+/// Something that scans a document's tokens for problems.
+pub trait Linter {
+    fn lint(&mut self, tokens: &TokenBuffer, source: &[char]) -> Vec<Lint>;
+}
+
+/// A collection of [`Linter`]s run together over the same document.
+#[derive(Default)]
+pub struct LintSet {
+    linters: Vec<Box<dyn Linter>>,
+}
+
+impl LintSet {
+    pub fn add(&mut self, linter: impl Linter + 'static) -> &mut Self {
+        self.linters.push(Box::new(linter));
+        self
+    }
+}
+
+impl Linter for LintSet {
+    fn lint(&mut self, tokens: &TokenBuffer, source: &[char]) -> Vec<Lint> {
+        self.linters
+            .iter_mut()
+            .flat_map(|linter| linter.lint(tokens, source))
+            .collect()
+    }
+}
@@ -0,0 +1,45 @@
+mod plain_english;
+mod typst;
+
+pub use plain_english::PlainEnglish;
+pub use typst::Typst;
+
+use crate::TokenBuffer;
+
+/// Transforms a sequence of characters into a [`TokenBuffer`].
+///
+/// Implementors should make no assumption about the boundaries of the slice
+/// they're given -- it may be a whole document, or a single paragraph carved
+/// out for incremental reparsing by [`crate::Document::apply_edit`].
+pub trait Parser {
+    fn parse(&mut self, source: &[char]) -> TokenBuffer;
+
+    /// Whether this parser's tokens never span a `Newline` -- i.e. lexing a
+    /// `Newline`-delimited paragraph in isolation always produces the same
+    /// tokens it would as part of the full document.
+    ///
+    /// [`crate::Document::apply_edit`]'s paragraph-granularity incremental
+    /// path is only sound when this holds, so it defaults to `false` and
+    /// parsers must opt in. `Typst`, for example, has constructs like
+    /// `#emph[line one\nline two]` whose `Newline` sits inside a single
+    /// bracketed node, so reparsing just the paragraph on one side of it
+    /// would cut the construct in half; it keeps the default.
+    fn is_paragraph_local(&self) -> bool {
+        false
+    }
+}
+
+/// Convenience for running a [`Parser`] over a [`str`] rather than a `&[char]`.
+pub trait StrParser {
+    fn parse_str(&mut self, source: &str) -> TokenBuffer;
+}
+
+impl<T> StrParser for T
+where
+    T: Parser,
+{
+    fn parse_str(&mut self, source: &str) -> TokenBuffer {
+        let source: Vec<_> = source.chars().collect();
+        self.parse(&source)
+    }
+}
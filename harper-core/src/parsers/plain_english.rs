@@ -0,0 +1,65 @@
+use super::Parser;
+use crate::{Punctuation, Span, TokenBuffer, TokenKind, WordMetadata};
+
+/// A tokenizer for plain English prose.
+///
+/// It walks the source once, grouping runs of alphanumeric characters into
+/// [`TokenKind::Word`]s, runs of non-newline whitespace into
+/// [`TokenKind::Space`], runs of line breaks into [`TokenKind::Newline`], and
+/// everything else into individual [`TokenKind::Punctuation`] tokens.
+pub struct PlainEnglish;
+
+impl Parser for PlainEnglish {
+    fn parse(&mut self, source: &[char]) -> TokenBuffer {
+        let mut tokens = TokenBuffer::new();
+        let mut cursor = 0;
+
+        while cursor < source.len() {
+            let c = source[cursor];
+
+            if c.is_alphanumeric() {
+                let start = cursor;
+                while cursor < source.len() && source[cursor].is_alphanumeric() {
+                    cursor += 1;
+                }
+                tokens.push(Span::new(start, cursor), TokenKind::Word(WordMetadata::default()));
+            } else if c == '\n' {
+                let start = cursor;
+                let mut count = 0;
+                while cursor < source.len() && source[cursor] == '\n' {
+                    count += 1;
+                    cursor += 1;
+                }
+                tokens.push(Span::new(start, cursor), TokenKind::Newline(count));
+            } else if c.is_whitespace() {
+                let start = cursor;
+                while cursor < source.len() && source[cursor].is_whitespace() && source[cursor] != '\n'
+                {
+                    cursor += 1;
+                }
+                tokens.push(Span::new(start, cursor), TokenKind::Space(cursor - start));
+            } else {
+                let punct = match c {
+                    '.' => Punctuation::Period,
+                    ',' => Punctuation::Comma,
+                    ':' => Punctuation::Colon,
+                    ';' => Punctuation::Semicolon,
+                    '!' => Punctuation::Exclamation,
+                    '?' => Punctuation::Question,
+                    '\'' | '"' => Punctuation::Quote,
+                    other => Punctuation::Other(other),
+                };
+                tokens.push(Span::new(cursor, cursor + 1), TokenKind::Punctuation(punct));
+                cursor += 1;
+            }
+        }
+
+        tokens
+    }
+
+    fn is_paragraph_local(&self) -> bool {
+        // Every token this parser produces comes from a single contiguous
+        // run of one character class, so it can never span a `Newline`.
+        true
+    }
+}
@@ -1,112 +1,93 @@
 use super::{Parser, PlainEnglish};
-use crate::{Span, Token, TokenKind};
+use crate::{Span, TokenBuffer, TokenKind};
 
-use typst_syntax::{LinkedNode, Side, SyntaxKind};
+use typst_syntax::{LinkedNode, SyntaxKind};
 
 pub struct Typst;
 
 impl Typst {}
 
 impl Parser for Typst {
-    fn parse(&mut self, source: &[char]) -> Vec<Token> {
+    fn parse(&mut self, source: &[char]) -> TokenBuffer {
         let source_str: String = source.iter().collect();
         let typst_node = typst_syntax::parse(&source_str);
         let root_node = LinkedNode::new(&typst_node);
 
-        let mut tokens = Vec::new();
-
-        // To find all the nodes, we can search for nodes by their offset from the beginning of
-        // the source file. We know to stop searching when the node comes to the end of the root
-        // source node's length.
-        let source_node_length = typst_node.len();
-        let mut cursor: usize = 0;
-        // In some situations, we want to disable linting for a while (i.e. in match mode)
-        let mut disable_linting: Option<SyntaxKind> = None;
-        while cursor < source_node_length {
-            let current_node = root_node.leaf_at(cursor, Side::After).unwrap();
-            let range = current_node.range();
-
-            if disable_linting.is_some_and(|t| t == current_node.kind()) {
-                // We have reached the matching node that will signal that we can start linting
-                // again. We still have to mark this node as Unlintable though.
-                disable_linting = None;
-                tokens.push(Token {
-                    span: Span::new_with_len(cursor, range.end),
-                    kind: TokenKind::Unlintable,
-                });
-                cursor = range.end;
-                continue;
-            } else if disable_linting.is_some() {
-                // We still want to keep linting disabled, but we need to mark this node as
-                // Unlintable.
-                tokens.push(Token {
-                    span: Span::new_with_len(cursor, range.end),
-                    kind: TokenKind::Unlintable,
-                });
-                cursor = range.end;
-                continue;
-            }
+        let mut tokens = TokenBuffer::new();
+        Self::parse_node(&root_node, source, &mut tokens);
+        tokens
+    }
+}
 
-            match current_node.kind() {
-                SyntaxKind::Text => {
-                    // For all text we can just use the standard English parser
-                    let mut engligh_parser = PlainEnglish;
+impl Typst {
+    /// Recursively walk `node`, pushing tokens for it and its descendants
+    /// into `tokens`.
+    ///
+    /// Nodes whose entire subtree should be ignored by every linter --
+    /// `Math`/`Equation`, `Raw`, `Code`, and comments -- are pushed as a
+    /// single `Unlintable` token spanning the whole node and are not
+    /// descended into, so nested constructs (a `$...$` inside a code block,
+    /// two raw blocks in a row, ...) are each handled by their own call
+    /// rather than through shared, kind-keyed state. Every other node with
+    /// children (content blocks, function calls, ...) is descended into, so
+    /// prose nested inside them -- e.g. `#emph[real prose]` -- is still
+    /// reached and linted.
+    fn parse_node(node: &LinkedNode, source: &[char], tokens: &mut TokenBuffer) {
+        let range = node.range();
+
+        match node.kind() {
+            SyntaxKind::Math
+            | SyntaxKind::Equation
+            | SyntaxKind::Raw
+            | SyntaxKind::Code
+            | SyntaxKind::LineComment
+            | SyntaxKind::BlockComment => {
+                tokens.push(Span::new_with_len(range.start, range.end), TokenKind::Unlintable);
+            }
+            SyntaxKind::Text => {
+                // For all text we can just use the standard English parser.
+                let mut english_parser = PlainEnglish;
 
-                    let mut new_tokens = engligh_parser.parse(&source[range.start..range.end]);
-                    // We need to update the spans of each token with the offset of the node from
-                    // the beginning of the source file
-                    new_tokens
-                        .iter_mut()
-                        .for_each(|token| token.span.push_by(range.start));
+                let mut new_tokens = english_parser.parse(&source[range.start..range.end]);
+                // We need to update the spans of each token with the offset of the node from
+                // the beginning of the source file.
+                new_tokens.push_by(range.start);
 
-                    tokens.append(&mut new_tokens);
-                }
-                SyntaxKind::Space | SyntaxKind::Parbreak => {
-                    // The Typst syntax uses a space as a representation of any whitespace. This
-                    // be used in scenarios where you need to separate different operators or text.
-                    let count = current_node.text().matches("\n").count();
-                    if count > 0 {
-                        tokens.push(Token {
-                            span: Span::new(cursor, range.end),
-                            // We want to add an additional newline to signify and end of a linting
-                            // section
-                            kind: TokenKind::Newline(count + 1),
-                        });
-                    } else {
-                        tokens.push(Token {
-                            span: Span::new(cursor, range.end),
-                            kind: TokenKind::Space(range.end - cursor),
-                        })
-                    }
+                tokens.extend(new_tokens);
+            }
+            SyntaxKind::Space | SyntaxKind::Parbreak => {
+                // The Typst syntax uses a space as a representation of any whitespace. This
+                // be used in scenarios where you need to separate different operators or text.
+                let count = node.text().matches('\n').count();
+                if count > 0 {
+                    // We want to add an additional newline to signify and end of a linting
+                    // section.
+                    tokens.push(Span::new(range.start, range.end), TokenKind::Newline(count + 1));
+                } else {
+                    tokens.push(
+                        Span::new(range.start, range.end),
+                        TokenKind::Space(range.end - range.start),
+                    );
                 }
-                SyntaxKind::Dollar | SyntaxKind::RawDelim => {
-                    disable_linting = Some(current_node.kind());
-                    tokens.push(Token {
-                        span: Span::new_with_len(cursor, range.end),
-                        kind: TokenKind::Unlintable,
-                    });
+            }
+            // All markers are unlintable.
+            SyntaxKind::ListMarker
+            | SyntaxKind::HeadingMarker
+            | SyntaxKind::Underscore
+            | SyntaxKind::Star
+            | SyntaxKind::LeftBracket
+            | SyntaxKind::RightBracket => {
+                tokens.push(Span::new(range.start, range.end), TokenKind::Unlintable);
+            }
+            _ if node.children().next().is_some() => {
+                // A markup/content/code node we don't have a specific rule for -- descend
+                // so any prose nested inside (e.g. `#emph[real prose]`) still gets linted.
+                for child in node.children() {
+                    Self::parse_node(&child, source, tokens);
                 }
-                // All markers are unlintable
-                SyntaxKind::ListMarker
-                | SyntaxKind::HeadingMarker
-                | SyntaxKind::Underscore
-                | SyntaxKind::Star
-                | SyntaxKind::LeftBracket
-                | SyntaxKind::RightBracket => tokens.push(Token {
-                    span: Span::new(cursor, range.end),
-                    kind: TokenKind::Unlintable,
-                }),
-                _ => tokens.push(Token {
-                    span: Span::new(cursor, range.end),
-                    kind: TokenKind::Unlintable,
-                }),
             }
-
-            // Mover cursor to end of node
-            cursor = range.end;
+            _ => tokens.push(Span::new(range.start, range.end), TokenKind::Unlintable),
         }
-
-        tokens
     }
 }
 
@@ -114,11 +95,11 @@ impl Parser for Typst {
 mod tests {
     use super::super::StrParser;
     use super::Typst;
-    use crate::{Punctuation, Token, TokenKind};
+    use crate::{Punctuation, TokenBuffer, TokenKind};
 
-    fn all_chars_tokenized(tokens: &Vec<Token>) -> bool {
+    fn all_chars_tokenized(tokens: &TokenBuffer) -> bool {
         let mut cursor = 0;
-        for token in tokens {
+        for token in tokens.iter() {
             if token.span.start > cursor + 1 {
                 return false;
             }
@@ -221,12 +202,13 @@ mod tests {
         let source = "Let $x = 27$.";
 
         let tokens = Typst.parse_str(source);
+        // The whole equation is now skipped as a single subtree, rather than leaf-by-leaf.
         assert_eq!(
             tokens
                 .iter()
                 .filter(|token| token.kind.is_unlintable())
                 .count(),
-            7
+            1
         );
         assert!(all_chars_tokenized(&tokens));
         assert!(tokens.last().unwrap().span.end == source.len());
@@ -238,14 +220,21 @@ mod tests {
                 TokenKind::Word(_),
                 TokenKind::Space(1),
                 TokenKind::Unlintable,
-                TokenKind::Unlintable,
-                TokenKind::Unlintable,
-                TokenKind::Unlintable,
-                TokenKind::Unlintable,
-                TokenKind::Unlintable,
-                TokenKind::Unlintable,
                 TokenKind::Punctuation(Punctuation::Period),
             ]
         ));
     }
+
+    #[test]
+    fn nested_math_in_code() {
+        // A math block nested inside a code block used to confuse the old
+        // kind-keyed `disable_linting` state machine (both ended on a
+        // different closer, but it only tracked one kind at a time). The
+        // recursive descent instead skips each subtree independently.
+        let source = "#{ $x$ }text after";
+
+        let tokens = Typst.parse_str(source);
+        assert!(all_chars_tokenized(&tokens));
+        assert!(tokens.last().unwrap().span.end == source.len());
+    }
 }
@@ -0,0 +1,47 @@
+/// A half-open range of character indices into a [`crate::Document`]'s source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    pub fn new_with_len(start: usize, end: usize) -> Self {
+        debug_assert!(end >= start);
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Whether `self` fully encloses `other`.
+    pub fn contains(&self, other: &Span) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn get_content<'a>(&self, source: &'a [char]) -> &'a [char] {
+        &source[self.start..self.end]
+    }
+
+    /// Shift both ends of the span forward by `offset` characters.
+    pub fn push_by(&mut self, offset: usize) {
+        self.start += offset;
+        self.end += offset;
+    }
+
+    /// Shift both ends of the span by a (possibly negative) character delta,
+    /// as happens when an earlier edit shortens or lengthens the source.
+    pub fn push_by_signed(&mut self, delta: isize) {
+        self.start = (self.start as isize + delta) as usize;
+        self.end = (self.end as isize + delta) as usize;
+    }
+}
@@ -0,0 +1,261 @@
+use crate::Span;
+
+/// A lexical unit produced by a [`crate::parsers::Parser`], together with its
+/// [`Span`] in the source it was lexed from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Token {
+    pub span: Span,
+    pub kind: TokenKind,
+}
+
+/// A [`Token`] that owns its content rather than pointing back into a source
+/// buffer. Useful once the original `&[char]` has gone out of scope.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FatToken {
+    pub content: Vec<char>,
+    pub kind: TokenKind,
+}
+
+/// Part-of-speech and other annotations attached to a [`TokenKind::Word`].
+///
+/// Left empty for now -- populated by the dictionary lookup passes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WordMetadata {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TokenKind {
+    Word(WordMetadata),
+    Punctuation(Punctuation),
+    /// A run of non-newline whitespace, with its length in characters.
+    Space(usize),
+    /// A run of consecutive line breaks, with the count of breaks.
+    Newline(usize),
+    /// A token that should be ignored by every [`crate::Linter`], such as
+    /// markup syntax or math/code spans.
+    Unlintable,
+}
+
+impl TokenKind {
+    pub fn is_newline(&self) -> bool {
+        matches!(self, Self::Newline(_))
+    }
+
+    pub fn is_unlintable(&self) -> bool {
+        matches!(self, Self::Unlintable)
+    }
+
+    pub fn is_word(&self) -> bool {
+        matches!(self, Self::Word(_))
+    }
+
+    pub fn is_punctuation(&self) -> bool {
+        matches!(self, Self::Punctuation(_))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Punctuation {
+    Period,
+    Comma,
+    Colon,
+    Semicolon,
+    Exclamation,
+    Question,
+    Quote,
+    Other(char),
+}
+
+/// Convenience helpers for walking a [`TokenBuffer`].
+pub trait TokenStringExt {
+    /// Iterate over every token whose [`TokenKind`] is lintable, i.e. not
+    /// [`TokenKind::Unlintable`].
+    fn iter_lintable(&self) -> impl Iterator<Item = Token> + '_;
+}
+
+impl TokenStringExt for TokenBuffer {
+    fn iter_lintable(&self) -> impl Iterator<Item = Token> + '_ {
+        self.iter().filter(|token| !token.kind.is_unlintable())
+    }
+}
+
+/// A struct-of-arrays token stream.
+///
+/// [`Parser`](crate::parsers::Parser)s used to collect a `Vec<Token>`, but
+/// the linting passes and [`TokenStringExt`] walk that stream many times
+/// over, and a `Vec` of fat `Token` structs scatters the field each pass
+/// actually needs (usually just the `TokenKind`) across a larger stride than
+/// necessary. `TokenBuffer` instead keeps kinds and spans in parallel arrays
+/// and only materializes a [`Token`] on access, which is friendlier to the
+/// cache and smaller in memory.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TokenBuffer {
+    kinds: Vec<TokenKind>,
+    starts: Vec<usize>,
+    lens: Vec<usize>,
+}
+
+impl TokenBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, span: Span, kind: TokenKind) {
+        self.starts.push(span.start);
+        self.lens.push(span.len());
+        self.kinds.push(kind);
+    }
+
+    pub fn len(&self) -> usize {
+        self.kinds.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Token> {
+        let start = *self.starts.get(index)?;
+        let len = *self.lens.get(index)?;
+        Some(Token {
+            span: Span::new_with_len(start, start + len),
+            kind: *self.kinds.get(index)?,
+        })
+    }
+
+    /// Materialize the [`Token`] at `index` into a [`FatToken`] that owns its
+    /// content, by copying it out of `source`.
+    pub fn get_fat(&self, index: usize, source: &[char]) -> Option<FatToken> {
+        let token = self.get(index)?;
+        Some(FatToken {
+            content: token.span.get_content(source).to_vec(),
+            kind: token.kind,
+        })
+    }
+
+    pub fn first(&self) -> Option<Token> {
+        self.get(0)
+    }
+
+    pub fn last(&self) -> Option<Token> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Token> + '_ {
+        (0..self.len()).map(move |i| self.get(i).unwrap())
+    }
+
+    /// Shift the spans of every token forward by `offset` characters.
+    pub fn push_by(&mut self, offset: usize) {
+        self.shift_from(0, offset as isize);
+    }
+
+    /// Shift the spans of every token from `index` onward by `delta`
+    /// characters, as happens when an edit earlier in the document changes
+    /// its length.
+    pub fn shift_from(&mut self, index: usize, delta: isize) {
+        for start in &mut self.starts[index..] {
+            *start = (*start as isize + delta) as usize;
+        }
+    }
+
+    /// Append `other`'s tokens to the end of this buffer.
+    pub fn extend(&mut self, other: TokenBuffer) {
+        self.kinds.extend(other.kinds);
+        self.starts.extend(other.starts);
+        self.lens.extend(other.lens);
+    }
+
+    /// Replace the tokens in `range` with `replacement`'s tokens, like
+    /// `Vec::splice`.
+    pub fn splice(&mut self, range: std::ops::Range<usize>, replacement: TokenBuffer) {
+        self.kinds.splice(range.clone(), replacement.kinds);
+        self.starts.splice(range.clone(), replacement.starts);
+        self.lens.splice(range, replacement.lens);
+    }
+}
+
+impl FromIterator<Token> for TokenBuffer {
+    fn from_iter<I: IntoIterator<Item = Token>>(iter: I) -> Self {
+        let mut buffer = Self::new();
+        for token in iter {
+            buffer.push(token.span, token.kind);
+        }
+        buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Punctuation, Token, TokenBuffer, TokenKind, TokenStringExt, WordMetadata};
+    use crate::Span;
+
+    fn sample() -> TokenBuffer {
+        let mut buffer = TokenBuffer::new();
+        buffer.push(Span::new(0, 4), TokenKind::Word(WordMetadata::default()));
+        buffer.push(Span::new(4, 5), TokenKind::Unlintable);
+        buffer.push(
+            Span::new(5, 6),
+            TokenKind::Punctuation(Punctuation::Period),
+        );
+        buffer
+    }
+
+    #[test]
+    fn get_and_iter_round_trip() {
+        let buffer = sample();
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(
+            buffer.get(0),
+            Some(Token {
+                span: Span::new(0, 4),
+                kind: TokenKind::Word(WordMetadata::default()),
+            })
+        );
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![
+            buffer.get(0).unwrap(),
+            buffer.get(1).unwrap(),
+            buffer.get(2).unwrap(),
+        ]);
+        assert!(buffer.get(3).is_none());
+    }
+
+    #[test]
+    fn shift_from_only_moves_later_tokens() {
+        let mut buffer = sample();
+
+        buffer.shift_from(1, 2);
+
+        assert_eq!(buffer.get(0).unwrap().span, Span::new(0, 4));
+        assert_eq!(buffer.get(1).unwrap().span, Span::new(6, 7));
+        assert_eq!(buffer.get(2).unwrap().span, Span::new(7, 8));
+    }
+
+    #[test]
+    fn splice_replaces_a_token_range() {
+        let mut buffer = sample();
+        let mut replacement = TokenBuffer::new();
+        replacement.push(Span::new(4, 7), TokenKind::Word(WordMetadata::default()));
+
+        buffer.splice(1..2, replacement);
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(
+            buffer.get(1),
+            Some(Token {
+                span: Span::new(4, 7),
+                kind: TokenKind::Word(WordMetadata::default()),
+            })
+        );
+    }
+
+    #[test]
+    fn iter_lintable_skips_unlintable_tokens() {
+        let buffer = sample();
+
+        let lintable: Vec<_> = buffer.iter_lintable().collect();
+
+        assert_eq!(lintable.len(), 2);
+        assert!(lintable.iter().all(|token| !token.kind.is_unlintable()));
+    }
+}